@@ -0,0 +1,58 @@
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        eprintln!($($arg)*)
+    };
+}
+
+mod print;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Sort {
+    None,
+    Name,
+    Size,
+}
+
+impl Default for Sort {
+    fn default() -> Self {
+        Sort::None
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Options {
+    pub(crate) print_source: bool,
+    pub(crate) print_value: bool,
+    pub(crate) output_json: bool,
+    pub(crate) ignore_variable_linkage_name: bool,
+    pub(crate) ignore_variable_symbol_name: bool,
+    pub(crate) ignore_variable_address: bool,
+    pub(crate) sort: Sort,
+}
+
+#[derive(Debug)]
+pub(crate) struct Error(String);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error(e.to_string())
+    }
+}
+
+impl From<std::fmt::Error> for Error {
+    fn from(e: std::fmt::Error) -> Self {
+        Error(e.to_string())
+    }
+}
+
+pub(crate) type Result<T> = std::result::Result<T, Error>;
+
+fn main() {}
@@ -0,0 +1,319 @@
+use std::cmp::Ordering;
+use std::io::{self, Write};
+
+use parser::FileHash;
+
+use crate::{Options, Result};
+
+pub(crate) mod namespace;
+pub(crate) mod source;
+pub(crate) mod types;
+pub(crate) mod variable;
+
+pub(crate) trait ValuePrinter: Write {
+    fn name(&mut self, name: &str) -> Result<()>;
+    fn link(
+        &mut self,
+        id: usize,
+        f: &mut dyn FnMut(&mut dyn ValuePrinter) -> Result<()>,
+    ) -> Result<()>;
+}
+
+pub(crate) struct TextPrinter<W: Write>(W);
+
+impl<W: Write> Write for TextPrinter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+impl<W: Write> ValuePrinter for TextPrinter<W> {
+    fn name(&mut self, name: &str) -> Result<()> {
+        write!(self, "{name}")?;
+        Ok(())
+    }
+
+    fn link(
+        &mut self,
+        _id: usize,
+        f: &mut dyn FnMut(&mut dyn ValuePrinter) -> Result<()>,
+    ) -> Result<()> {
+        f(self)
+    }
+}
+
+fn render<F>(hash: &FileHash, f: F) -> Result<Vec<u8>>
+where
+    F: FnOnce(&mut dyn ValuePrinter, &FileHash) -> Result<()>,
+{
+    let mut printer = TextPrinter(Vec::new());
+    f(&mut printer, hash)?;
+    Ok(printer.0)
+}
+
+/// Renders a single value-printing callback (one that doesn't need a
+/// `FileHash`) into an owned buffer, e.g. to embed its text inside a larger
+/// structured (JSON) field rather than writing it straight to the output.
+pub(crate) fn render_value<F>(f: F) -> Result<Vec<u8>>
+where
+    F: FnOnce(&mut dyn ValuePrinter) -> Result<()>,
+{
+    let mut printer = TextPrinter(Vec::new());
+    f(&mut printer)?;
+    Ok(printer.0)
+}
+
+pub(crate) struct PrintState<'a, 'input> {
+    w: &'a mut dyn ValuePrinter,
+    hash: &'a FileHash<'input>,
+    options: &'a Options,
+    indent: usize,
+}
+
+impl<'a, 'input> PrintState<'a, 'input> {
+    pub(crate) fn new(
+        w: &'a mut dyn ValuePrinter,
+        hash: &'a FileHash<'input>,
+        options: &'a Options,
+    ) -> Self {
+        PrintState {
+            w,
+            hash,
+            options,
+            indent: 0,
+        }
+    }
+
+    pub(crate) fn options(&self) -> &Options {
+        self.options
+    }
+
+    fn write_indent(&mut self) -> Result<()> {
+        for _ in 0..self.indent {
+            write!(self.w, "\t")?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn line<F>(&mut self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut dyn ValuePrinter, &FileHash) -> Result<()>,
+    {
+        self.write_indent()?;
+        f(self.w, self.hash)?;
+        writeln!(self.w)?;
+        Ok(())
+    }
+
+    pub(crate) fn field<F>(&mut self, label: &str, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut dyn ValuePrinter, &FileHash) -> Result<()>,
+    {
+        let value = render(self.hash, f)?;
+        if !value.is_empty() {
+            self.write_indent()?;
+            write!(self.w, "{label}: ")?;
+            self.w.write_all(&value)?;
+            writeln!(self.w)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn id<H, B>(&mut self, id: usize, header: H, body: B) -> Result<()>
+    where
+        H: FnOnce(&mut Self) -> Result<()>,
+        B: FnOnce(&mut Self) -> Result<()>,
+    {
+        let _ = id;
+        header(self)?;
+        self.indent += 1;
+        let result = body(self);
+        self.indent -= 1;
+        result
+    }
+
+    pub(crate) fn line_break(&mut self) -> Result<()> {
+        writeln!(self.w)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct DiffState<'a, 'input> {
+    w: &'a mut dyn ValuePrinter,
+    hash_a: &'a FileHash<'input>,
+    hash_b: &'a FileHash<'input>,
+    options: &'a Options,
+    indent: usize,
+    ignore: bool,
+}
+
+impl<'a, 'input> DiffState<'a, 'input> {
+    pub(crate) fn new(
+        w: &'a mut dyn ValuePrinter,
+        hash_a: &'a FileHash<'input>,
+        hash_b: &'a FileHash<'input>,
+        options: &'a Options,
+    ) -> Self {
+        DiffState {
+            w,
+            hash_a,
+            hash_b,
+            options,
+            indent: 0,
+            ignore: false,
+        }
+    }
+
+    pub(crate) fn options(&self) -> &Options {
+        self.options
+    }
+
+    pub(crate) fn hash_a(&self) -> &FileHash<'input> {
+        self.hash_a
+    }
+
+    pub(crate) fn hash_b(&self) -> &FileHash<'input> {
+        self.hash_b
+    }
+
+    fn write_indent(&mut self) -> Result<()> {
+        for _ in 0..self.indent {
+            write!(self.w, "\t")?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn line<T, F>(&mut self, a: &T, b: &T, f: F) -> Result<()>
+    where
+        F: Fn(&mut dyn ValuePrinter, &FileHash, &T) -> Result<()>,
+    {
+        let value_a = render(self.hash_a, |w, hash| f(w, hash, a))?;
+        let value_b = render(self.hash_b, |w, hash| f(w, hash, b))?;
+        self.write_indent()?;
+        if value_a == value_b {
+            self.w.write_all(&value_a)?;
+            writeln!(self.w)?;
+        } else {
+            writeln!(self.w, "- {}", String::from_utf8_lossy(&value_a))?;
+            self.write_indent()?;
+            writeln!(self.w, "+ {}", String::from_utf8_lossy(&value_b))?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn field<T, F>(&mut self, label: &str, a: &T, b: &T, f: F) -> Result<()>
+    where
+        F: Fn(&mut dyn ValuePrinter, &FileHash, &T) -> Result<()>,
+    {
+        if self.ignore {
+            return Ok(());
+        }
+        let value_a = render(self.hash_a, |w, hash| f(w, hash, a))?;
+        let value_b = render(self.hash_b, |w, hash| f(w, hash, b))?;
+        if value_a.is_empty() && value_b.is_empty() {
+            return Ok(());
+        }
+        self.write_indent()?;
+        if value_a == value_b {
+            write!(self.w, "{label}: ")?;
+            self.w.write_all(&value_a)?;
+            writeln!(self.w)?;
+        } else {
+            write!(self.w, "- {label}: ")?;
+            self.w.write_all(&value_a)?;
+            writeln!(self.w)?;
+            self.write_indent()?;
+            write!(self.w, "+ {label}: ")?;
+            self.w.write_all(&value_b)?;
+            writeln!(self.w)?;
+        }
+        Ok(())
+    }
+
+    /// Writes one raw, unduplicated line. Unlike `line`/`field`, which render
+    /// the callback once per side and diff the two text results, this runs
+    /// the callback exactly once — for modes (like JSON) that build their
+    /// own merged a/b representation instead of two text columns.
+    pub(crate) fn line_raw<F>(&mut self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut dyn ValuePrinter) -> Result<()>,
+    {
+        self.write_indent()?;
+        f(self.w)?;
+        writeln!(self.w)?;
+        Ok(())
+    }
+
+    pub(crate) fn ignore_diff<F>(&mut self, flag: bool, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Self) -> Result<()>,
+    {
+        let prev = self.ignore;
+        self.ignore = self.ignore || flag;
+        let result = f(self);
+        self.ignore = prev;
+        result
+    }
+
+    pub(crate) fn collapsed<H, B>(&mut self, header: H, body: B) -> Result<()>
+    where
+        H: FnOnce(&mut Self) -> Result<()>,
+        B: FnOnce(&mut Self) -> Result<()>,
+    {
+        header(self)?;
+        self.indent += 1;
+        let result = body(self);
+        self.indent -= 1;
+        result
+    }
+
+    pub(crate) fn line_break(&mut self) -> Result<()> {
+        writeln!(self.w)?;
+        Ok(())
+    }
+}
+
+pub(crate) trait Print {
+    type Arg;
+
+    fn print(&self, state: &mut PrintState, arg: &Self::Arg) -> Result<()>;
+
+    fn diff(
+        state: &mut DiffState,
+        arg_a: &Self::Arg,
+        a: &Self,
+        arg_b: &Self::Arg,
+        b: &Self,
+    ) -> Result<()>
+    where
+        Self: Sized;
+}
+
+pub(crate) trait PrintHeader {
+    fn print_header(&self, state: &mut PrintState) -> Result<()>;
+    fn print_body(&self, state: &mut PrintState, unit: &parser::Unit) -> Result<()>;
+
+    fn diff_header(state: &mut DiffState, a: &Self, b: &Self) -> Result<()>
+    where
+        Self: Sized;
+    fn diff_body(
+        state: &mut DiffState,
+        unit_a: &parser::Unit,
+        a: &Self,
+        unit_b: &parser::Unit,
+        b: &Self,
+    ) -> Result<()>
+    where
+        Self: Sized;
+}
+
+pub(crate) trait SortList: Sized {
+    fn cmp_id(hash_a: &FileHash, a: &Self, hash_b: &FileHash, b: &Self, options: &Options)
+        -> Ordering;
+    fn cmp_by(hash_a: &FileHash, a: &Self, hash_b: &FileHash, b: &Self, options: &Options)
+        -> Ordering;
+}
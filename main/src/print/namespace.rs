@@ -0,0 +1,11 @@
+use parser::Namespace;
+
+use crate::print::ValuePrinter;
+use crate::Result;
+
+pub(crate) fn print(namespace: &Namespace, w: &mut dyn ValuePrinter) -> Result<()> {
+    for part in namespace.parts() {
+        write!(w, "{part}::")?;
+    }
+    Ok(())
+}
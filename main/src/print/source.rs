@@ -0,0 +1,14 @@
+use parser::{Source, Unit};
+
+use crate::print::ValuePrinter;
+use crate::Result;
+
+pub(crate) fn print(source: &Source, w: &mut dyn ValuePrinter, unit: &Unit) -> Result<()> {
+    if let Some(file) = source.file(unit) {
+        write!(w, "{file}")?;
+        if let Some(line) = source.line() {
+            write!(w, ":{line}")?;
+        }
+    }
+    Ok(())
+}
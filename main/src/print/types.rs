@@ -0,0 +1,154 @@
+use parser::{BaseEncoding, FileHash, Type};
+
+use crate::print::ValuePrinter;
+use crate::Result;
+
+pub(crate) fn print_ref(ty: &Type, w: &mut dyn ValuePrinter, _hash: &FileHash) -> Result<()> {
+    w.name(ty.name())?;
+    Ok(())
+}
+
+/// Decodes and prints `ty`'s value out of `bytes`, which must hold at least
+/// `ty.byte_size()` bytes. `depth` bounds struct/array recursion (pointers
+/// are printed as an address and never followed) and
+/// `max_array_len` bounds how many array elements are printed, so a huge or
+/// self-referential layout can't blow up the output.
+pub(crate) fn print_value(
+    ty: &Type,
+    w: &mut dyn ValuePrinter,
+    hash: &FileHash,
+    bytes: &[u8],
+    depth: usize,
+    max_array_len: usize,
+) -> Result<()> {
+    if depth == 0 {
+        write!(w, "...")?;
+        return Ok(());
+    }
+    match ty {
+        Type::Base {
+            encoding,
+            byte_size,
+            ..
+        } => print_base_value(*encoding, *byte_size, bytes, w),
+        Type::Pointer { byte_size, .. } => {
+            let address = read_uint(bytes, *byte_size as usize);
+            match address.and_then(|a| hash.symbol_name_at(a)) {
+                Some(name) => write!(w, "0x{:x} <{name}>", address.unwrap())?,
+                None => match address {
+                    Some(address) => write!(w, "0x{address:x}")?,
+                    None => write!(w, "<invalid>")?,
+                },
+            }
+            Ok(())
+        }
+        Type::Array {
+            element,
+            count,
+            byte_size: _,
+        } => {
+            let element_size = element.byte_size() as usize;
+            let count = count
+                .map(|c| c as usize)
+                .unwrap_or_else(|| bytes.len().checked_div(element_size).unwrap_or(0));
+            write!(w, "[")?;
+            for i in 0..count.min(max_array_len) {
+                if i != 0 {
+                    write!(w, ", ")?;
+                }
+                let start = i * element_size;
+                let end = start + element_size;
+                match bytes.get(start..end) {
+                    Some(element_bytes) => {
+                        print_value(element, w, hash, element_bytes, depth - 1, max_array_len)?
+                    }
+                    None => write!(w, "?")?,
+                }
+            }
+            if count > max_array_len {
+                write!(w, ", ...")?;
+            }
+            write!(w, "]")?;
+            Ok(())
+        }
+        Type::Struct { members, .. } => {
+            write!(w, "{{")?;
+            for (i, member) in members.iter().enumerate() {
+                if i != 0 {
+                    write!(w, ", ")?;
+                }
+                write!(w, "{}: ", member.name)?;
+                let size = member.ty.byte_size() as usize;
+                let start = member.offset as usize;
+                match bytes.get(start..start + size) {
+                    Some(member_bytes) => print_value(
+                        &member.ty,
+                        w,
+                        hash,
+                        member_bytes,
+                        depth - 1,
+                        max_array_len,
+                    )?,
+                    None => write!(w, "?")?,
+                }
+            }
+            write!(w, "}}")?;
+            Ok(())
+        }
+        Type::Unknown { .. } => {
+            write!(w, "<unknown>")?;
+            Ok(())
+        }
+    }
+}
+
+fn print_base_value(
+    encoding: BaseEncoding,
+    byte_size: u64,
+    bytes: &[u8],
+    w: &mut dyn ValuePrinter,
+) -> Result<()> {
+    match encoding {
+        BaseEncoding::Bool => {
+            write!(w, "{}", bytes.first().copied().unwrap_or(0) != 0)?;
+        }
+        BaseEncoding::UnsignedInt => match read_uint(bytes, byte_size as usize) {
+            Some(value) => write!(w, "{value}")?,
+            None => write!(w, "<invalid>")?,
+        },
+        BaseEncoding::SignedInt => match read_uint(bytes, byte_size as usize) {
+            Some(value) => write!(w, "{}", sign_extend(value, byte_size))?,
+            None => write!(w, "<invalid>")?,
+        },
+        BaseEncoding::Float => match (byte_size, bytes.len()) {
+            (4, len) if len >= 4 => {
+                write!(w, "{}", f32::from_le_bytes(bytes[..4].try_into().unwrap()))?
+            }
+            (8, len) if len >= 8 => {
+                write!(w, "{}", f64::from_le_bytes(bytes[..8].try_into().unwrap()))?
+            }
+            _ => write!(w, "<invalid>")?,
+        },
+    }
+    Ok(())
+}
+
+fn read_uint(bytes: &[u8], size: usize) -> Option<u64> {
+    if size == 0 || size > 8 || bytes.len() < size {
+        return None;
+    }
+    let mut value = 0u64;
+    for (i, &byte) in bytes[..size].iter().enumerate() {
+        value |= (byte as u64) << (8 * i);
+    }
+    Some(value)
+}
+
+fn sign_extend(value: u64, byte_size: u64) -> i64 {
+    let bits = (byte_size * 8).min(64);
+    if bits == 0 || bits == 64 {
+        return value as i64;
+    }
+    let shift = 64 - bits;
+    ((value << shift) as i64) >> shift
+}
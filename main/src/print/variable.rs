@@ -10,29 +10,73 @@ pub(crate) fn print_ref(v: &Variable, w: &mut dyn ValuePrinter) -> Result<()> {
         if let Some(namespace) = v.namespace() {
             print::namespace::print(namespace, w)?;
         }
-        w.name(v.name().unwrap_or("<anon>"))?;
+        w.name(&display_name(v))?;
         Ok(())
     })
 }
 
+/// `v`'s name, qualified with its block depth (`x#2`) when it shadows a
+/// same-named local declared in a shallower block of the same subprogram --
+/// so both are shown distinctly rather than one hiding the other.
+fn display_name(v: &Variable) -> String {
+    let name = v.name().unwrap_or("<anon>");
+    if v.shadow_index() > 0 {
+        format!("{name}#{}", v.scope().depth())
+    } else {
+        name.to_owned()
+    }
+}
+
 impl<'input> PrintHeader for Variable<'input> {
     fn print_header(&self, state: &mut PrintState) -> Result<()> {
+        if state.options().output_json {
+            return Ok(());
+        }
         state.line(|w, state| print_name(self, w, state))
     }
 
     fn print_body(&self, state: &mut PrintState, unit: &Unit) -> Result<()> {
+        if state.options().output_json {
+            let print_source = state.options().print_source;
+            let print_value = state.options().print_value;
+            let include_scope = self.scope().is_local();
+            return state.line(|w, hash| {
+                let fields = json_fields(
+                    self,
+                    hash,
+                    unit,
+                    print_source,
+                    print_value,
+                    include_scope,
+                    false,
+                    false,
+                    false,
+                )?;
+                write_json_object(w, &fields)
+            });
+        }
         state.field("linkage name", |w, _state| print_linkage_name(self, w))?;
         state.field("symbol name", |w, _state| print_symbol_name(self, w))?;
         if state.options().print_source {
             state.field("source", |w, _state| print_source(self, w, unit))?;
         }
-        state.field("address", |w, _state| print_address(self, w))?;
+        state.field("address", |w, _state| print_address(self, w, unit))?;
+        if self.scope().is_local() {
+            state.field("scope", |w, _state| print_scope(self, w))?;
+            state.field("range", |w, _state| print_range(self, w))?;
+        }
         state.field("size", |w, state| print_size(self, w, state))?;
+        if state.options().print_value {
+            state.field("value", |w, hash| print_value(self, w, hash, unit))?;
+        }
         state.field("declaration", |w, _state| print_declaration(self, w))
         // TODO: print anon type inline
     }
 
     fn diff_header(state: &mut DiffState, a: &Self, b: &Self) -> Result<()> {
+        if state.options().output_json {
+            return Ok(());
+        }
         state.line(a, b, |w, state, x| print_name(x, w, state))
     }
 
@@ -43,6 +87,37 @@ impl<'input> PrintHeader for Variable<'input> {
         unit_b: &parser::Unit,
         b: &Self,
     ) -> Result<()> {
+        if state.options().output_json {
+            let print_source = state.options().print_source;
+            let print_value = state.options().print_value;
+            let include_scope = a.scope().is_local() || b.scope().is_local();
+            let ignore_linkage_name = state.options().ignore_variable_linkage_name;
+            let ignore_symbol_name = state.options().ignore_variable_symbol_name;
+            let ignore_address = state.options().ignore_variable_address;
+            let fields_a = json_fields(
+                a,
+                state.hash_a(),
+                unit_a,
+                print_source,
+                print_value,
+                include_scope,
+                ignore_linkage_name,
+                ignore_symbol_name,
+                ignore_address,
+            )?;
+            let fields_b = json_fields(
+                b,
+                state.hash_b(),
+                unit_b,
+                print_source,
+                print_value,
+                include_scope,
+                ignore_linkage_name,
+                ignore_symbol_name,
+                ignore_address,
+            )?;
+            return state.line_raw(|w| print_json_diff(w, &fields_a, &fields_b));
+        }
         let flag = state.options().ignore_variable_linkage_name;
         state.ignore_diff(flag, |state| {
             state.field("linkage name", a, b, |w, _state, x| {
@@ -56,16 +131,33 @@ impl<'input> PrintHeader for Variable<'input> {
         if state.options().print_source {
             state.field(
                 "source",
-                (unit_a, a),
-                (unit_b, b),
+                &(unit_a, a),
+                &(unit_b, b),
                 |w, _state, (unit, x)| print_source(x, w, unit),
             )?;
         }
         let flag = state.options().ignore_variable_address;
         state.ignore_diff(flag, |state| {
-            state.field("address", a, b, |w, _state, x| print_address(x, w))
+            state.field(
+                "address",
+                &(unit_a, a),
+                &(unit_b, b),
+                |w, _state, (unit, x)| print_address(x, w, unit),
+            )
         })?;
+        if a.scope().is_local() || b.scope().is_local() {
+            state.field("scope", a, b, |w, _state, x| print_scope(x, w))?;
+            state.field("range", a, b, |w, _state, x| print_range(x, w))?;
+        }
         state.field("size", a, b, |w, state, x| print_size(x, w, state))?;
+        if state.options().print_value {
+            state.field(
+                "value",
+                &(unit_a, a),
+                &(unit_b, b),
+                |w, hash, (unit, x)| print_value(x, w, hash, unit),
+            )?;
+        }
         state.field("declaration", a, b, |w, _state, x| print_declaration(x, w))
     }
 }
@@ -100,7 +192,7 @@ fn print_name(v: &Variable, w: &mut dyn ValuePrinter, hash: &FileHash) -> Result
     if let Some(namespace) = v.namespace() {
         print::namespace::print(namespace, w)?;
     }
-    w.name(v.name().unwrap_or("<anon>"))?;
+    w.name(&display_name(v))?;
     write!(w, ": ")?;
     print::types::print_ref(v.ty(hash), w, hash)?;
     Ok(())
@@ -124,13 +216,284 @@ fn print_source(v: &Variable, w: &mut dyn ValuePrinter, unit: &Unit) -> Result<(
     print::source::print(v.source(), w, unit)
 }
 
-fn print_address(v: &Variable, w: &mut dyn ValuePrinter) -> Result<()> {
+/// Renders `v`'s address field: a human-readable location (`reg rdi`,
+/// `fbreg-24`, `tls:0x..`, or a `<piece0@.., piece1@..>` composite) when a
+/// `DW_AT_location` expression is present and understood, otherwise the bare
+/// load address.
+fn print_address(v: &Variable, w: &mut dyn ValuePrinter, unit: &Unit) -> Result<()> {
+    if let Some(text) = location_text(v, unit) {
+        write!(w, "{text}")?;
+        return Ok(());
+    }
     if let Some(address) = v.address() {
         write!(w, "0x{address:x}")?;
     }
     Ok(())
 }
 
+fn location_text(v: &Variable, unit: &Unit) -> Option<String> {
+    let expr = v.location_expression()?;
+    let location = eval_location(expr, unit.address_size())?;
+    Some(format_location(&location, true))
+}
+
+/// Renders a local's enclosing subprogram and lexical-block path, e.g.
+/// `main/0/1` for a block nested inside the first block of `main`. Prints
+/// nothing for a file-scope variable.
+fn print_scope(v: &Variable, w: &mut dyn ValuePrinter) -> Result<()> {
+    let scope = v.scope();
+    if let Some(subprogram) = scope.subprogram() {
+        write!(w, "{subprogram}")?;
+        for index in scope.path() {
+            write!(w, "/{index}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Renders a local's PC liveness ranges, e.g. `[0x401120, 0x401150)`, or
+/// several comma-separated if it has more than one.
+fn print_range(v: &Variable, w: &mut dyn ValuePrinter) -> Result<()> {
+    for (i, range) in v.live_ranges().iter().enumerate() {
+        if i != 0 {
+            write!(w, ", ")?;
+        }
+        write!(w, "[0x{:x}, 0x{:x})", range.begin, range.end)?;
+    }
+    Ok(())
+}
+
+const DW_OP_ADDR: u8 = 0x03;
+const DW_OP_REG0: u8 = 0x50;
+const DW_OP_REG31: u8 = 0x6f;
+const DW_OP_BREG0: u8 = 0x70;
+const DW_OP_BREG31: u8 = 0x8f;
+const DW_OP_REGX: u8 = 0x90;
+const DW_OP_FBREG: u8 = 0x91;
+const DW_OP_PIECE: u8 = 0x93;
+const DW_OP_FORM_TLS_ADDRESS: u8 = 0x9b;
+const DW_OP_GNU_PUSH_TLS_ADDRESS: u8 = 0xe0;
+
+/// The result of evaluating a `DW_AT_location` expression.
+#[derive(Debug, Clone, PartialEq)]
+enum Location {
+    /// A fixed memory address (`DW_OP_addr`).
+    Address(u64),
+    /// A value held in a register (`DW_OP_regN`/`DW_OP_regx`).
+    Register(u8),
+    /// A signed offset from the frame base (`DW_OP_fbreg`).
+    FrameOffset(i64),
+    /// A signed offset from a register (`DW_OP_bregN`).
+    RegisterOffset(u8, i64),
+    /// A thread-local offset (`DW_OP_GNU_push_tls_address`/`DW_OP_form_tls_address`).
+    Tls(u64),
+    /// A value split across multiple locations (`DW_OP_piece`).
+    Pieces(Vec<LocationPiece>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct LocationPiece {
+    location: Option<Location>,
+    byte_size: Option<u64>,
+}
+
+/// Tracks the location built up so far while evaluating an expression: the
+/// single value most recent operations have produced, used either as the
+/// final result or as the operand of a following `DW_OP_piece`/tls op.
+enum Pending {
+    None,
+    Location(Location),
+}
+
+/// A small cursor over a DWARF expression's bytes, used to read the
+/// fixed-width and LEB128-encoded operands of its opcodes.
+struct Cursor<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Cursor { data }
+    }
+
+    fn read_u8(&mut self) -> Option<u8> {
+        let (&byte, rest) = self.data.split_first()?;
+        self.data = rest;
+        Some(byte)
+    }
+
+    fn read_uint(&mut self, size: usize) -> Option<u64> {
+        if size == 0 || size > 8 || self.data.len() < size {
+            return None;
+        }
+        let mut value = 0u64;
+        for (i, &byte) in self.data[..size].iter().enumerate() {
+            value |= (byte as u64) << (8 * i);
+        }
+        self.data = &self.data[size..];
+        Some(value)
+    }
+
+    /// Reads an unsigned LEB128 value. Anything this doesn't recognise --
+    /// including an operand wider than 64 bits -- evaluates to `None` rather
+    /// than erroring or panicking, per this module's usual contract for
+    /// malformed/untrusted DWARF input.
+    fn read_uleb128(&mut self) -> Option<u64> {
+        let mut result = 0u64;
+        let mut shift = 0u32;
+        loop {
+            let byte = self.read_u8()?;
+            if shift >= 64 || (shift == 63 && byte & 0x7e != 0) {
+                return None;
+            }
+            result |= ((byte & 0x7f) as u64) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        Some(result)
+    }
+
+    /// Reads a signed LEB128 value. See `read_uleb128` for the overflow contract.
+    fn read_sleb128(&mut self) -> Option<i64> {
+        let mut result = 0i64;
+        let mut shift = 0u32;
+        let last_byte;
+        loop {
+            let byte = self.read_u8()?;
+            if shift >= 64 || (shift == 63 && byte & 0x7f != 0 && byte & 0x7f != 0x7f) {
+                return None;
+            }
+            result |= ((byte & 0x7f) as i64) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                last_byte = byte;
+                break;
+            }
+        }
+        if shift < 64 && last_byte & 0x40 != 0 {
+            result |= -1i64 << shift;
+        }
+        Some(result)
+    }
+}
+
+/// Interprets a `DW_AT_location` expression, returning the single location
+/// (or composite of pieces) it describes. Anything it doesn't recognise --
+/// an unsupported opcode, a truncated operand, or a malformed LEB128 value --
+/// evaluates to `None` rather than erroring.
+fn eval_location(expr: &[u8], address_size: u8) -> Option<Location> {
+    let mut cursor = Cursor::new(expr);
+    let mut pending = Pending::None;
+    let mut pieces = Vec::new();
+    while let Some(op) = cursor.read_u8() {
+        match op {
+            DW_OP_ADDR => {
+                let address = cursor.read_uint(address_size as usize)?;
+                pending = Pending::Location(Location::Address(address));
+            }
+            DW_OP_FBREG => {
+                let offset = cursor.read_sleb128()?;
+                pending = Pending::Location(Location::FrameOffset(offset));
+            }
+            DW_OP_REG0..=DW_OP_REG31 => {
+                pending = Pending::Location(Location::Register(op - DW_OP_REG0));
+            }
+            DW_OP_BREG0..=DW_OP_BREG31 => {
+                let offset = cursor.read_sleb128()?;
+                pending = Pending::Location(Location::RegisterOffset(op - DW_OP_BREG0, offset));
+            }
+            DW_OP_REGX => {
+                let register = cursor.read_uleb128()?;
+                pending = Pending::Location(Location::Register(register as u8));
+            }
+            DW_OP_GNU_PUSH_TLS_ADDRESS | DW_OP_FORM_TLS_ADDRESS => {
+                if let Pending::Location(Location::Address(address)) = pending {
+                    pending = Pending::Location(Location::Tls(address));
+                }
+            }
+            DW_OP_PIECE => {
+                let byte_size = cursor.read_uleb128()?;
+                let location = match std::mem::replace(&mut pending, Pending::None) {
+                    Pending::Location(location) => Some(location),
+                    Pending::None => None,
+                };
+                pieces.push(LocationPiece {
+                    location,
+                    byte_size: Some(byte_size),
+                });
+            }
+            _ => return None,
+        }
+    }
+
+    if pieces.is_empty() {
+        match pending {
+            Pending::Location(location) => Some(location),
+            Pending::None => None,
+        }
+    } else {
+        if let Pending::Location(location) = pending {
+            pieces.push(LocationPiece {
+                location: Some(location),
+                byte_size: None,
+            });
+        }
+        Some(Location::Pieces(pieces))
+    }
+}
+
+/// x86-64 DWARF register numbers for the names `ddbug` knows; anything else
+/// is printed as `r{n}`.
+fn register_name(register: u8) -> String {
+    const NAMES: [&str; 16] = [
+        "rax", "rdx", "rcx", "rbx", "rsi", "rdi", "rbp", "rsp", "r8", "r9", "r10", "r11", "r12",
+        "r13", "r14", "r15",
+    ];
+    match NAMES.get(register as usize) {
+        Some(name) => (*name).to_owned(),
+        None => format!("r{register}"),
+    }
+}
+
+/// Formats an evaluated location as text. `top_level` distinguishes the
+/// standalone `Address` case (printed as a bare `0x..`, matching a variable
+/// with no location expression at all) from an `Address` nested inside a
+/// `Pieces` composite (printed as `mem 0x..`, to disambiguate it from a
+/// register piece).
+fn format_location(location: &Location, top_level: bool) -> String {
+    match location {
+        Location::Address(address) => {
+            if top_level {
+                format!("0x{address:x}")
+            } else {
+                format!("mem 0x{address:x}")
+            }
+        }
+        Location::Register(register) => format!("reg {}", register_name(*register)),
+        Location::FrameOffset(offset) => format!("fbreg{offset:+}"),
+        Location::RegisterOffset(register, offset) => {
+            format!("breg {}{offset:+}", register_name(*register))
+        }
+        Location::Tls(address) => format!("tls:0x{address:x}"),
+        Location::Pieces(pieces) => {
+            let parts: Vec<String> = pieces
+                .iter()
+                .enumerate()
+                .map(|(i, piece)| {
+                    let text = match &piece.location {
+                        Some(location) => format_location(location, false),
+                        None => "?".to_owned(),
+                    };
+                    format!("piece{i}@{text}")
+                })
+                .collect();
+            format!("<{}>", parts.join(", "))
+        }
+    }
+}
+
 fn print_size(v: &Variable, w: &mut dyn ValuePrinter, hash: &FileHash) -> Result<()> {
     if let Some(byte_size) = v.byte_size(hash) {
         write!(w, "{byte_size}")?;
@@ -140,6 +503,25 @@ fn print_size(v: &Variable, w: &mut dyn ValuePrinter, hash: &FileHash) -> Result
     Ok(())
 }
 
+/// Maximum array elements `print_value` will render before truncating with `...`.
+const MAX_ARRAY_LEN: usize = 16;
+
+/// Maximum struct/array nesting `print_value` will recurse through.
+const MAX_VALUE_DEPTH: usize = 8;
+
+fn value_bytes<'a>(v: &Variable, hash: &FileHash, unit: &'a Unit) -> Option<&'a [u8]> {
+    let address = v.address()?;
+    let byte_size = v.byte_size(hash)?;
+    unit.data_at(address, byte_size)
+}
+
+fn print_value(v: &Variable, w: &mut dyn ValuePrinter, hash: &FileHash, unit: &Unit) -> Result<()> {
+    if let Some(bytes) = value_bytes(v, hash, unit) {
+        print::types::print_value(v.ty(hash), w, hash, bytes, MAX_VALUE_DEPTH, MAX_ARRAY_LEN)?;
+    }
+    Ok(())
+}
+
 fn print_declaration(v: &Variable, w: &mut dyn ValuePrinter) -> Result<()> {
     if v.is_declaration() {
         write!(w, "yes")?;
@@ -147,6 +529,177 @@ fn print_declaration(v: &Variable, w: &mut dyn ValuePrinter) -> Result<()> {
     Ok(())
 }
 
+/// Builds `v`'s JSON representation as an ordered list of
+/// `(key, json-encoded value)` pairs rather than writing it straight out, so
+/// a diff can compare the two sides field-by-field (see `print_json_diff`)
+/// instead of only ever comparing the whole serialized object.
+#[allow(clippy::too_many_arguments)]
+fn json_fields(
+    v: &Variable,
+    hash: &FileHash,
+    unit: &Unit,
+    print_source: bool,
+    print_value: bool,
+    include_scope: bool,
+    ignore_linkage_name: bool,
+    ignore_symbol_name: bool,
+    ignore_address: bool,
+) -> Result<Vec<(&'static str, String)>> {
+    let mut fields = Vec::new();
+    fields.push(("kind", json_string("variable")));
+    fields.push((
+        "name",
+        match v.name() {
+            Some(_) => json_string(&display_name(v)),
+            None => "null".to_owned(),
+        },
+    ));
+    if !ignore_linkage_name {
+        fields.push(("linkage_name", json_opt_string(v.linkage_name())));
+    }
+    if !ignore_symbol_name {
+        fields.push(("symbol_name", json_opt_string(v.symbol_name())));
+    }
+    if print_source {
+        fields.push(("source", json_source(v, unit)));
+    }
+    if !ignore_address {
+        fields.push((
+            "address",
+            match location_text(v, unit).or_else(|| v.address().map(|a| format!("0x{a:x}"))) {
+                Some(text) => json_string(&text),
+                None => "null".to_owned(),
+            },
+        ));
+    }
+    if include_scope {
+        fields.push(("scope", json_scope(v)));
+        fields.push(("range", json_range(v)));
+    }
+    fields.push((
+        "size",
+        match v.byte_size(hash) {
+            Some(byte_size) => byte_size.to_string(),
+            None => "null".to_owned(),
+        },
+    ));
+    if print_value {
+        fields.push(("value", json_value(v, hash, unit)?));
+    }
+    fields.push(("declaration", v.is_declaration().to_string()));
+    Ok(fields)
+}
+
+fn json_scope(v: &Variable) -> String {
+    let scope = v.scope();
+    match scope.subprogram() {
+        Some(subprogram) => {
+            let mut text = subprogram.to_owned();
+            for index in scope.path() {
+                text.push_str(&format!("/{index}"));
+            }
+            json_string(&text)
+        }
+        None => "null".to_owned(),
+    }
+}
+
+fn json_range(v: &Variable) -> String {
+    let ranges: Vec<String> = v
+        .live_ranges()
+        .iter()
+        .map(|range| format!("[0x{:x}, 0x{:x})", range.begin, range.end))
+        .collect();
+    json_string(&ranges.join(", "))
+}
+
+/// Renders `v`'s decoded value (see `print_value`) into a JSON string, or
+/// `null` if its bytes aren't available (e.g. no address, or it's in BSS).
+fn json_value(v: &Variable, hash: &FileHash, unit: &Unit) -> Result<String> {
+    match value_bytes(v, hash, unit) {
+        Some(bytes) => {
+            let text = print::render_value(|w| {
+                print::types::print_value(v.ty(hash), w, hash, bytes, MAX_VALUE_DEPTH, MAX_ARRAY_LEN)
+            })?;
+            Ok(json_string(&String::from_utf8_lossy(&text)))
+        }
+        None => Ok("null".to_owned()),
+    }
+}
+
+fn write_json_object(w: &mut dyn ValuePrinter, fields: &[(&str, String)]) -> Result<()> {
+    write!(w, "{{")?;
+    for (i, (key, value)) in fields.iter().enumerate() {
+        if i != 0 {
+            write!(w, ",")?;
+        }
+        write!(w, "\"{key}\":{value}")?;
+    }
+    write!(w, "}}")?;
+    Ok(())
+}
+
+/// Writes a merged diff object: fields that render identically on both sides
+/// are emitted once (`"key":value`); fields that differ are emitted as
+/// `"key":{"a":value,"b":value}`. `fields_a`/`fields_b` must come from
+/// `json_fields` calls made with the same flags, so they share the same keys
+/// in the same order.
+fn print_json_diff(
+    w: &mut dyn ValuePrinter,
+    fields_a: &[(&str, String)],
+    fields_b: &[(&str, String)],
+) -> Result<()> {
+    write!(w, "{{")?;
+    for (i, ((key, value_a), (_, value_b))) in fields_a.iter().zip(fields_b.iter()).enumerate() {
+        if i != 0 {
+            write!(w, ",")?;
+        }
+        if value_a == value_b {
+            write!(w, "\"{key}\":{value_a}")?;
+        } else {
+            write!(w, "\"{key}\":{{\"a\":{value_a},\"b\":{value_b}}}")?;
+        }
+    }
+    write!(w, "}}")?;
+    Ok(())
+}
+
+fn json_source(v: &Variable, unit: &Unit) -> String {
+    let source = v.source();
+    format!(
+        "{{\"file\":{},\"line\":{}}}",
+        json_opt_string(source.file(unit)),
+        match source.line() {
+            Some(line) => line.to_string(),
+            None => "null".to_owned(),
+        }
+    )
+}
+
+fn json_opt_string(s: Option<&str>) -> String {
+    match s {
+        Some(s) => json_string(s),
+        None => "null".to_owned(),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
 impl<'input> Print for Variable<'input> {
     type Arg = Unit<'input>;
 
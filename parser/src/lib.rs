@@ -0,0 +1,357 @@
+//! Minimal DWARF-adjacent data model shared by the `main` crate's printers.
+//!
+//! This crate owns the parsed representation of debug info: units and the
+//! variables they declare, both file-scope globals and locals scoped to a
+//! subprogram's lexical blocks. `main` only ever reads this data.
+
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+/// Per-file context shared across a print or diff: the symbol table used to
+/// resolve addresses (e.g. a pointer's target) to names.
+#[derive(Debug, Clone, Default)]
+pub struct FileHash<'input> {
+    symbols: Vec<(u64, String)>,
+    _marker: PhantomData<&'input ()>,
+}
+
+impl<'input> FileHash<'input> {
+    pub fn new(symbols: Vec<(u64, String)>) -> Self {
+        FileHash {
+            symbols,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn symbol_name_at(&self, address: u64) -> Option<&str> {
+        self.symbols
+            .iter()
+            .find(|(a, _)| *a == address)
+            .map(|(_, name)| name.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Namespace(Vec<String>);
+
+impl Namespace {
+    pub fn new(parts: Vec<String>) -> Self {
+        Namespace(parts)
+    }
+
+    pub fn parts(&self) -> &[String] {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Source {
+    file: Option<String>,
+    line: Option<u64>,
+}
+
+impl Source {
+    pub fn new(file: Option<String>, line: Option<u64>) -> Self {
+        Source { file, line }
+    }
+
+    pub fn file(&self, _unit: &Unit) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn line(&self) -> Option<u64> {
+        self.line
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BaseEncoding {
+    SignedInt,
+    UnsignedInt,
+    Float,
+    Bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Member {
+    pub name: String,
+    pub offset: u64,
+    pub ty: Type,
+}
+
+#[derive(Debug, Clone)]
+pub enum Type {
+    Base {
+        name: String,
+        encoding: BaseEncoding,
+        byte_size: u64,
+    },
+    Pointer {
+        name: String,
+        byte_size: u64,
+    },
+    Array {
+        element: Box<Type>,
+        count: Option<u64>,
+        byte_size: u64,
+    },
+    Struct {
+        name: String,
+        members: Vec<Member>,
+        byte_size: u64,
+    },
+    Unknown {
+        name: String,
+    },
+}
+
+impl Type {
+    pub fn name(&self) -> &str {
+        match self {
+            Type::Base { name, .. }
+            | Type::Pointer { name, .. }
+            | Type::Struct { name, .. }
+            | Type::Unknown { name, .. } => name,
+            Type::Array { .. } => "[]",
+        }
+    }
+
+    pub fn byte_size(&self) -> u64 {
+        match self {
+            Type::Base { byte_size, .. }
+            | Type::Pointer { byte_size, .. }
+            | Type::Struct { byte_size, .. }
+            | Type::Array { byte_size, .. } => *byte_size,
+            Type::Unknown { .. } => 0,
+        }
+    }
+}
+
+/// A PC range in which a local variable is live, e.g. `[0x401120, 0x401150)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Range {
+    pub begin: u64,
+    pub end: u64,
+}
+
+/// Identifies a local variable's enclosing lexical block: the subprogram it
+/// lives in, and the path of child-block indices from that subprogram's
+/// root to the block that directly declares it (e.g. `[0, 1]` is the second
+/// block nested inside the first top-level block). File-scope variables
+/// have no subprogram and an empty path. Two locals with the same name but
+/// different scopes always compare unequal -- this is what diff uses to
+/// match locals across versions, and what tells shadowed locals apart.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Scope {
+    subprogram: Option<String>,
+    path: Vec<usize>,
+}
+
+impl Scope {
+    pub fn new(subprogram: Option<String>, path: Vec<usize>) -> Self {
+        Scope { subprogram, path }
+    }
+
+    pub fn subprogram(&self) -> Option<&str> {
+        self.subprogram.as_deref()
+    }
+
+    pub fn path(&self) -> &[usize] {
+        &self.path
+    }
+
+    /// The lexical nesting depth of this scope: `0` for a variable declared
+    /// directly in its subprogram's root, `1` for one nested a block deep,
+    /// and so on.
+    pub fn depth(&self) -> usize {
+        self.path.len()
+    }
+
+    pub fn is_local(&self) -> bool {
+        self.subprogram.is_some()
+    }
+}
+
+/// A loaded section of the binary, used to read a global's initial bytes.
+#[derive(Debug, Clone)]
+pub struct Section {
+    pub address: u64,
+    pub size: u64,
+    pub data: Vec<u8>,
+    pub bss: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Unit<'input> {
+    sections: Vec<Section>,
+    address_size: u8,
+    _marker: PhantomData<&'input ()>,
+}
+
+impl<'input> Unit<'input> {
+    pub fn new(sections: Vec<Section>, address_size: u8) -> Self {
+        Unit {
+            sections,
+            address_size,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The size in bytes of a machine address in this unit, e.g. `8` on
+    /// x86-64. Used to decode `DW_OP_addr` operands in location expressions.
+    pub fn address_size(&self) -> u8 {
+        self.address_size
+    }
+
+    /// Returns the raw bytes for `address..address+size`, or `None` if the
+    /// range isn't covered by any loaded section or falls in a
+    /// zero-initialized (BSS) section.
+    pub fn data_at(&self, address: u64, size: u64) -> Option<&[u8]> {
+        for section in &self.sections {
+            if address < section.address {
+                continue;
+            }
+            let offset = address - section.address;
+            if offset.checked_add(size)? > section.size {
+                continue;
+            }
+            if section.bss {
+                return None;
+            }
+            let offset = offset as usize;
+            return section.data.get(offset..offset + size as usize);
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Variable<'input> {
+    id: usize,
+    name: Option<String>,
+    linkage_name: Option<String>,
+    symbol_name: Option<String>,
+    namespace: Option<Namespace>,
+    source: Source,
+    address: Option<u64>,
+    byte_size: Option<u64>,
+    declaration: bool,
+    ty: Type,
+    location: Option<Vec<u8>>,
+    scope: Scope,
+    live_ranges: Vec<Range>,
+    shadow_index: usize,
+    _marker: PhantomData<&'input ()>,
+}
+
+impl<'input> Variable<'input> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: usize,
+        name: Option<String>,
+        linkage_name: Option<String>,
+        symbol_name: Option<String>,
+        namespace: Option<Namespace>,
+        source: Source,
+        address: Option<u64>,
+        byte_size: Option<u64>,
+        declaration: bool,
+        ty: Type,
+        location: Option<Vec<u8>>,
+    ) -> Self {
+        Variable {
+            id,
+            name,
+            linkage_name,
+            symbol_name,
+            namespace,
+            source,
+            address,
+            byte_size,
+            declaration,
+            ty,
+            location,
+            scope: Scope::default(),
+            live_ranges: Vec::new(),
+            shadow_index: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Marks this variable as a local: attaches the lexical block it lives
+    /// in, the PC ranges over which it's live, and its shadow index -- the
+    /// number of same-named locals in shallower blocks of the same
+    /// subprogram that it shadows, used to tell shadowed locals apart when
+    /// displaying them rather than collapsing them together.
+    pub fn with_scope(mut self, scope: Scope, live_ranges: Vec<Range>, shadow_index: usize) -> Self {
+        self.scope = scope;
+        self.live_ranges = live_ranges;
+        self.shadow_index = shadow_index;
+        self
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn linkage_name(&self) -> Option<&str> {
+        self.linkage_name.as_deref()
+    }
+
+    pub fn symbol_name(&self) -> Option<&str> {
+        self.symbol_name.as_deref()
+    }
+
+    pub fn namespace(&self) -> Option<&Namespace> {
+        self.namespace.as_ref()
+    }
+
+    pub fn source(&self) -> &Source {
+        &self.source
+    }
+
+    pub fn address(&self) -> Option<u64> {
+        self.address
+    }
+
+    pub fn byte_size(&self, _hash: &FileHash) -> Option<u64> {
+        self.byte_size
+    }
+
+    pub fn is_declaration(&self) -> bool {
+        self.declaration
+    }
+
+    pub fn ty(&self, _hash: &FileHash) -> &Type {
+        &self.ty
+    }
+
+    /// The raw `DW_AT_location` expression bytes, if the variable has one.
+    /// See `print::variable::eval_location` for the interpreter.
+    pub fn location_expression(&self) -> Option<&[u8]> {
+        self.location.as_deref()
+    }
+
+    pub fn scope(&self) -> &Scope {
+        &self.scope
+    }
+
+    pub fn live_ranges(&self) -> &[Range] {
+        &self.live_ranges
+    }
+
+    pub fn shadow_index(&self) -> usize {
+        self.shadow_index
+    }
+
+    pub fn cmp_id(_hash_a: &FileHash, a: &Variable, _hash_b: &FileHash, b: &Variable) -> Ordering {
+        a.namespace
+            .cmp(&b.namespace)
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.scope.cmp(&b.scope))
+    }
+}